@@ -5,70 +5,131 @@ pub fn clean_file_path(path: &str) -> String {
     path.trim_matches('"').trim_matches('\'').trim().to_string()
 }
 
+// 去除 Windows 上 canonicalize 返回的 verbatim（\\?\）前缀，还原为传统路径形式，
+// 否则 webview 的 convertFileSrc、shell 插件等消费者在展示/使用路径时会出问题
+#[cfg(windows)]
+fn strip_verbatim_prefix(path: &str) -> String {
+    const VERBATIM_PREFIX: &str = r"\\?\";
+    const VERBATIM_UNC_PREFIX: &str = r"\\?\UNC\";
+
+    if let Some(rest) = path.strip_prefix(VERBATIM_UNC_PREFIX) {
+        // \\?\UNC\server\share\... -> \\server\share\...
+        return format!(r"\\{}", rest);
+    }
+
+    if let Some(rest) = path.strip_prefix(VERBATIM_PREFIX) {
+        // \\?\C:\... -> C:\...，仅在形如 "C:" 的盘符前缀时才剥离
+        let mut chars = rest.chars();
+        let drive_letter = chars.next();
+        let colon = chars.next();
+        if matches!(drive_letter, Some(c) if c.is_ascii_alphabetic()) && colon == Some(':') {
+            return rest.to_string();
+        }
+    }
+
+    // 无法识别的 verbatim 路径，原样保留
+    path.to_string()
+}
+
+#[cfg(not(windows))]
+fn strip_verbatim_prefix(path: &str) -> String {
+    path.to_string()
+}
+
+// 纯字典序的绝对路径规范化，不访问文件系统，因此对尚不存在的文件（如"另存为"目标、
+// 新引用的链接）也能得到干净的绝对路径。相对路径先拼接 current_dir，再逐个 Component
+// 处理：Normal 入栈、CurDir 丢弃、ParentDir 弹出上一个 Normal（但不会越过 RootDir/Prefix）
+pub fn lexical_absolute_path(path: &std::path::Path) -> std::path::PathBuf {
+    use std::path::{Component, PathBuf};
+
+    let absolute = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        match std::env::current_dir() {
+            Ok(current_dir) => current_dir.join(path),
+            Err(_) => path.to_path_buf(),
+        }
+    };
+
+    let mut normalized = PathBuf::new();
+    for component in absolute.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                match normalized.components().last() {
+                    Some(Component::Normal(_)) => {
+                        normalized.pop();
+                    }
+                    _ => {
+                        // 已经到达 RootDir/Prefix，或没有可弹出的段，保留 ".."
+                        normalized.push(component);
+                    }
+                }
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
+
 // 将路径转换为绝对路径
 pub fn to_absolute_path(path: &str) -> String {
     use std::path::Path;
-    
+
     let cleaned = clean_file_path(path);
     let path_buf = Path::new(&cleaned);
-    
+
     // 如果已经是绝对路径，直接返回
     if path_buf.is_absolute() {
         // 即使是绝对路径，也尝试 canonicalize 来规范化（处理 .. 和 . 等）
         if let Ok(canonical) = std::fs::canonicalize(&path_buf) {
             if let Some(canonical_str) = canonical.to_str() {
-                return canonical_str.to_string();
+                return strip_verbatim_prefix(canonical_str);
             }
         }
-        return cleaned;
+        // canonicalize 失败（文件很可能还不存在，如"另存为"目标），同样退回纯字典序规范化，
+        // 而不是原样返回可能带有 ".."/"." 的路径
+        return match lexical_absolute_path(&path_buf).to_str() {
+            Some(lexical_str) => lexical_str.to_string(),
+            None => cleaned,
+        };
     }
-    
+
     println!("检测到相对路径: {}, 当前工作目录: {:?}", cleaned, std::env::current_dir());
-    
+
     // 如果是相对路径，尝试解析为绝对路径
     // 首先直接尝试 canonicalize（相对于当前工作目录）
     match std::fs::canonicalize(&path_buf) {
         Ok(absolute) => {
             if let Some(absolute_str) = absolute.to_str() {
+                let absolute_str = strip_verbatim_prefix(absolute_str);
                 println!("通过 canonicalize 得到绝对路径: {}", absolute_str);
-                return absolute_str.to_string();
+                return absolute_str;
             }
         }
         Err(e) => {
-            println!("canonicalize 失败 (路径可能不存在): {:?}, 尝试使用当前工作目录组合", e);
+            println!("canonicalize 失败 (路径可能不存在): {:?}, 使用纯字典序规范化兜底", e);
         }
     }
-    
-    // 如果 canonicalize 失败（文件可能还不存在），使用当前工作目录组合路径
-    match std::env::current_dir() {
-        Ok(current_dir) => {
-            let absolute = current_dir.join(&path_buf);
-            if let Some(absolute_str) = absolute.to_str() {
-                println!("使用当前工作目录组合路径: {}", absolute_str);
-                // 再次尝试 canonicalize 来规范化路径
-                match std::fs::canonicalize(&absolute) {
-                    Ok(canonical) => {
-                        if let Some(canonical_str) = canonical.to_str() {
-                            println!("规范化后的绝对路径: {}", canonical_str);
-                            return canonical_str.to_string();
-                        }
-                    }
-                    Err(_) => {
-                        // 如果文件不存在，返回组合后的绝对路径（仍然有效）
-                        println!("文件可能不存在，使用组合路径: {}", absolute_str);
-                    }
-                }
-                return absolute_str.to_string();
-            }
+
+    // canonicalize 失败（文件很可能还不存在），退回纯字典序规范化，
+    // 这样 ".." 和 "." 也能被正确解析，不需要访问文件系统
+    let lexical = lexical_absolute_path(&path_buf);
+    match lexical.to_str() {
+        Some(lexical_str) => {
+            println!("使用字典序规范化得到绝对路径: {}", lexical_str);
+            lexical_str.to_string()
         }
-        Err(e) => {
-            eprintln!("无法获取当前工作目录: {:?}", e);
+        None => {
+            println!("无法转换为绝对路径，返回原始路径: {}", cleaned);
+            cleaned
         }
     }
-    
-    // 如果都失败了，返回原始路径
-    println!("无法转换为绝对路径，返回原始路径: {}", cleaned);
-    cleaned
+}
+
+// 本应用用于缓存下载内容（远程文件、git 浅克隆等）的临时目录
+pub fn temp_directory_path() -> std::path::PathBuf {
+    std::env::temp_dir().join("dongshanMD")
 }
 
 // 检查是否是支持的文件类型
@@ -79,26 +140,73 @@ pub fn is_supported_file(path: &str) -> bool {
     lower.ends_with(".txt")
 }
 
-// 处理文件路径并发送事件
+// 处理单个文件路径并发送事件
 pub fn process_and_emit_file(app_handle: tauri::AppHandle, file_path: String) {
-    let cleaned_path = clean_file_path(&file_path);
-    let absolute_path = to_absolute_path(&cleaned_path);
-    println!("处理文件路径: {} -> {} -> {}", file_path, cleaned_path, absolute_path);
-    
-    if is_supported_file(&absolute_path) {
-        println!("发送文件打开事件: {}", absolute_path);
-        // 延迟发送，确保前端已准备好
-        std::thread::spawn(move || {
-            std::thread::sleep(std::time::Duration::from_millis(800));
+    process_and_emit_files(app_handle, vec![file_path]);
+}
+
+// 处理多个文件路径并分别发送事件，用于用户一次性打开/拖放多个文件的场景
+pub fn process_and_emit_files(app_handle: tauri::AppHandle, file_paths: Vec<String>) {
+    let absolute_paths: Vec<String> = file_paths
+        .into_iter()
+        .map(|file_path| {
+            let cleaned_path = clean_file_path(&file_path);
+            let absolute_path = to_absolute_path(&cleaned_path);
+            println!("处理文件路径: {} -> {} -> {}", file_path, cleaned_path, absolute_path);
+            absolute_path
+        })
+        .filter(|absolute_path| {
+            let supported = is_supported_file(absolute_path);
+            if !supported {
+                println!("不支持的文件类型: {}", absolute_path);
+            }
+            supported
+        })
+        .collect();
+
+    if absolute_paths.is_empty() {
+        return;
+    }
+
+    // 延迟发送，确保前端已准备好；多个文件共用同一次延迟，而不是每个文件各开一个线程
+    std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(800));
+        for absolute_path in absolute_paths {
+            println!("发送文件打开事件: {}", absolute_path);
             if let Err(e) = app_handle.emit("open-file", &absolute_path) {
                 eprintln!("发送文件打开事件失败: {:?}", e);
             } else {
                 println!("文件打开事件已发送: {}", absolute_path);
+                if let Err(e) = crate::watcher::start_watching(app_handle.clone(), &absolute_path) {
+                    eprintln!("注册文件监听失败: {}", e);
+                }
             }
-        });
-    } else {
-        println!("不支持的文件类型: {}", absolute_path);
+        }
+    });
+}
+
+// 处理多个文件夹路径并发送 open-folder 事件，让前端把文件夹渲染成侧边栏文件树
+pub fn process_and_emit_folders(app_handle: tauri::AppHandle, folder_paths: Vec<String>) {
+    let absolute_paths: Vec<String> = folder_paths
+        .into_iter()
+        .map(|folder_path| to_absolute_path(&clean_file_path(&folder_path)))
+        .collect();
+
+    if absolute_paths.is_empty() {
+        return;
     }
+
+    std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(800));
+        for absolute_path in absolute_paths {
+            println!("发送文件夹打开事件: {}", absolute_path);
+            if let Err(e) = app_handle.emit("open-folder", &absolute_path) {
+                eprintln!("发送文件夹打开事件失败: {:?}", e);
+            } else {
+                println!("文件夹打开事件已发送: {}", absolute_path);
+            }
+        }
+    });
 }
 
 
@@ -1,8 +1,74 @@
 // Tauri 命令定义
 // 这个模块专门用于定义 Tauri 命令，避免重复定义问题
 
+use crate::app;
+use crate::remote::{self, RemoteSource};
+use serde::Serialize;
+
 #[tauri::command]
 pub fn get_file_args() -> Vec<String> {
     std::env::args().skip(1).collect()
 }
 
+// 目录中单个条目的信息，供前端渲染侧边栏文件树使用
+#[derive(Serialize)]
+pub struct DirEntryInfo {
+    pub name: String,
+    pub path: String,
+    pub size: u64,
+    pub is_directory: bool,
+    pub modified: Option<u64>,
+    pub created: Option<u64>,
+    pub is_supported: bool,
+}
+
+fn unix_millis(time: std::io::Result<std::time::SystemTime>) -> Option<u64> {
+    time.ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_millis() as u64)
+}
+
+// 读取目录内容，将整个文件夹作为工作区打开时供前端渲染文件树
+#[tauri::command]
+pub fn list_directory(path: String) -> Result<Vec<DirEntryInfo>, String> {
+    let read_dir = std::fs::read_dir(&path).map_err(|e| format!("读取目录失败: {}", e))?;
+
+    let mut entries = Vec::new();
+    for entry in read_dir {
+        let entry = entry.map_err(|e| format!("读取目录条目失败: {}", e))?;
+        let metadata = entry.metadata().map_err(|e| format!("读取元数据失败: {}", e))?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        let absolute_path = app::to_absolute_path(&entry.path().to_string_lossy());
+        let is_directory = metadata.is_dir();
+
+        entries.push(DirEntryInfo {
+            is_supported: !is_directory && app::is_supported_file(&absolute_path),
+            name,
+            path: absolute_path,
+            size: metadata.len(),
+            is_directory,
+            modified: unix_millis(metadata.modified()),
+            created: unix_millis(metadata.created()),
+        });
+    }
+
+    Ok(entries)
+}
+
+// 打开一个远程 Markdown 来源：要么是原始文件的 https URL，要么是 git 仓库 + 仓库内路径
+#[tauri::command]
+pub async fn fetch_remote_source(app_handle: tauri::AppHandle, source: RemoteSource) -> Result<(), String> {
+    remote::fetch_remote_source(app_handle, source).await
+}
+
+// 让前端为某个已打开的标签页单独开启/关闭外部变更监听
+#[tauri::command]
+pub fn start_watching(app_handle: tauri::AppHandle, path: String) -> Result<(), String> {
+    crate::watcher::start_watching(app_handle, &path)
+}
+
+#[tauri::command]
+pub fn stop_watching(path: String) -> Result<(), String> {
+    crate::watcher::stop_watching(&path)
+}
+
@@ -1,29 +1,42 @@
 mod app;
-
-// 命令定义
-#[tauri::command]
-pub fn get_file_args() -> Vec<String> {
-    std::env::args().skip(1).collect()
-}
+mod commands;
+mod remote;
+mod watcher;
 
 pub fn run_app() {
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
-        .invoke_handler(tauri::generate_handler![get_file_args])
+        .invoke_handler(tauri::generate_handler![
+            commands::get_file_args,
+            commands::list_directory,
+            commands::fetch_remote_source,
+            commands::start_watching,
+            commands::stop_watching
+        ])
         .setup(|app| {
             // 处理通过文件关联打开的文件
             let app_handle = app.handle().clone();
-            
+
             // 获取命令行参数
             let args: Vec<String> = std::env::args().skip(1).collect();
             println!("收到命令行参数: {:?}", args);
-            
+
+            let cleaned_args: Vec<String> = args.iter().map(|arg| app::clean_file_path(arg)).collect();
+
+            // 命令行参数既可能是文件也可能是文件夹，分别路由到 open-file / open-folder
+            let (folder_args, file_args): (Vec<String>, Vec<String>) = cleaned_args
+                .into_iter()
+                .partition(|arg| std::path::Path::new(arg).is_dir());
+
+            for folder in &folder_args {
+                println!("找到文件夹: {}", folder);
+            }
+
             // 过滤出支持的文件类型（.md, .markdown, .txt）
-            // 注意：这里不转换为绝对路径，因为 is_supported_file 只检查扩展名
-            let file_args: Vec<String> = args.iter()
-                .map(|arg| app::clean_file_path(arg))
+            let file_args: Vec<String> = file_args
+                .into_iter()
                 .filter(|arg| {
                     let is_file = app::is_supported_file(arg);
                     if is_file {
@@ -32,18 +45,20 @@ pub fn run_app() {
                     is_file
                 })
                 .collect();
-            
+
+            if !folder_args.is_empty() {
+                app::process_and_emit_folders(app_handle.clone(), folder_args);
+            }
+
             if !file_args.is_empty() {
-                // 处理第一个文件
-                let file_path = file_args[0].clone();
-                app::process_and_emit_file(app_handle.clone(), file_path);
-            } else {
+                // 将所有文件都交给前端，各自作为独立的标签页打开
+                app::process_and_emit_files(app_handle.clone(), file_args);
+            } else if folder_args.is_empty() {
                 println!("未找到支持的文件类型");
             }
-            
+
             Ok(())
         })
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
-
@@ -6,20 +6,39 @@
 // 但为了避免命令定义冲突，我们使用共享的 commands 模块
 mod app;
 mod commands;
+mod remote;
+mod watcher;
 
 fn main() {
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
-        .invoke_handler(tauri::generate_handler![commands::get_file_args])
+        .invoke_handler(tauri::generate_handler![
+            commands::get_file_args,
+            commands::list_directory,
+            commands::fetch_remote_source,
+            commands::start_watching,
+            commands::stop_watching
+        ])
         .setup(|app| {
             let app_handle = app.handle().clone();
             let args: Vec<String> = std::env::args().skip(1).collect();
             println!("收到命令行参数: {:?}", args);
-            
-            let file_args: Vec<String> = args.iter()
-                .map(|arg| app::clean_file_path(arg))
+
+            let cleaned_args: Vec<String> = args.iter().map(|arg| app::clean_file_path(arg)).collect();
+
+            // 命令行参数既可能是文件也可能是文件夹，分别路由到 open-file / open-folder
+            let (folder_args, file_args): (Vec<String>, Vec<String>) = cleaned_args
+                .into_iter()
+                .partition(|arg| std::path::Path::new(arg).is_dir());
+
+            for folder in &folder_args {
+                println!("找到文件夹: {}", folder);
+            }
+
+            let file_args: Vec<String> = file_args
+                .into_iter()
                 .filter(|arg| {
                     let is_file = app::is_supported_file(arg);
                     if is_file {
@@ -28,14 +47,17 @@ fn main() {
                     is_file
                 })
                 .collect();
-            
+
+            if !folder_args.is_empty() {
+                app::process_and_emit_folders(app_handle.clone(), folder_args);
+            }
+
             if !file_args.is_empty() {
-                let file_path = file_args[0].clone();
-                app::process_and_emit_file(app_handle.clone(), file_path);
-            } else {
+                app::process_and_emit_files(app_handle.clone(), file_args);
+            } else if folder_args.is_empty() {
                 println!("未找到支持的文件类型");
             }
-            
+
             Ok(())
         })
         .run(tauri::generate_context!())
@@ -0,0 +1,164 @@
+// 打开远程 Markdown 来源：要么是指向原始文件的 https URL，要么是一个 git 仓库 + 路径
+
+use crate::app;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+#[derive(Deserialize)]
+pub struct GitSpec {
+    pub repo_url: String,
+    pub path: String,
+    pub branch: Option<String>,
+    pub revision: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct RemoteSource {
+    pub url: Option<String>,
+    pub git: Option<GitSpec>,
+}
+
+// 将任意来源最终落地为本地文件后，交给既有的 process_and_emit_file 流水线
+// （清理路径、转绝对路径、校验受支持的扩展名、发送 open-file），本地与远程文件共用一条路径
+pub async fn fetch_remote_source(app_handle: tauri::AppHandle, source: RemoteSource) -> Result<(), String> {
+    match (source.url, source.git) {
+        (Some(url), None) => fetch_from_url(app_handle, url).await,
+        (None, Some(git)) => fetch_from_git(app_handle, git),
+        (Some(_), Some(_)) => Err("url 和 git 只能指定一个".to_string()),
+        (None, None) => Err("必须指定 url 或 git".to_string()),
+    }
+}
+
+async fn fetch_from_url(app_handle: tauri::AppHandle, url: String) -> Result<(), String> {
+    println!("下载远程文件: {}", url);
+
+    let response = reqwest::get(&url).await.map_err(|e| format!("下载失败: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!("下载失败，状态码: {}", response.status()));
+    }
+    let body = response.text().await.map_err(|e| format!("读取响应内容失败: {}", e))?;
+
+    // 只取最后一个 "/" 段，再交给 Path::file_name() 过滤掉其中的路径分隔符和 ".."/"." 这类
+    // 穿越片段（Windows 上 "\" 也是分隔符），避免 temp_dir.join(file_name) 逃出临时目录
+    let last_segment = url.rsplit('/').next().unwrap_or("");
+    let file_name = Path::new(last_segment)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .filter(|name| !name.is_empty())
+        .unwrap_or("remote.md");
+
+    let temp_dir = app::temp_directory_path();
+    std::fs::create_dir_all(&temp_dir).map_err(|e| format!("创建临时目录失败: {}", e))?;
+    let temp_path = temp_dir.join(file_name);
+    std::fs::write(&temp_path, body).map_err(|e| format!("写入临时文件失败: {}", e))?;
+
+    app::process_and_emit_file(app_handle, temp_path.to_string_lossy().to_string());
+    Ok(())
+}
+
+fn fetch_from_git(app_handle: tauri::AppHandle, git: GitSpec) -> Result<(), String> {
+    let repo_dir = ensure_git_checkout(&git)?;
+    let source_path = repo_dir.join(&git.path);
+
+    if !source_path.is_file() {
+        return Err(format!("仓库中未找到文件: {}", git.path));
+    }
+
+    // git.path 可能是 "../../etc/passwd" 或绝对路径，canonicalize 后校验仍落在克隆目录内，
+    // 防止越权读取仓库目录之外的文件
+    let canonical_repo_dir = repo_dir
+        .canonicalize()
+        .map_err(|e| format!("解析仓库目录失败: {}", e))?;
+    let canonical_source_path = source_path
+        .canonicalize()
+        .map_err(|e| format!("解析文件路径失败: {}", e))?;
+
+    if !canonical_source_path.starts_with(&canonical_repo_dir) {
+        return Err(format!("拒绝访问仓库目录之外的路径: {}", git.path));
+    }
+
+    app::process_and_emit_file(app_handle, canonical_source_path.to_string_lossy().to_string());
+    Ok(())
+}
+
+// 用仓库地址的哈希作为缓存目录名，避免不同仓库相互覆盖
+fn git_cache_dir(repo_url: &str) -> PathBuf {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    repo_url.hash(&mut hasher);
+    app::temp_directory_path()
+        .join("git-cache")
+        .join(format!("{:x}", hasher.finish()))
+}
+
+// 校验仓库地址的 scheme，防止形如 "--upload-pack=..." 的字符串被 git 当成选项解析
+fn validate_repo_url(repo_url: &str) -> Result<(), String> {
+    let allowed = ["https://", "http://", "git@", "ssh://"];
+    if allowed.iter().any(|prefix| repo_url.starts_with(prefix)) {
+        Ok(())
+    } else {
+        Err(format!("不支持的仓库地址: {}", repo_url))
+    }
+}
+
+fn run_git(args: &[&str], cwd: Option<&Path>) -> Result<(), String> {
+    let mut command = std::process::Command::new("git");
+    command.args(args);
+    if let Some(dir) = cwd {
+        command.current_dir(dir);
+    }
+
+    let status = command.status().map_err(|e| format!("执行 git 命令失败: {:?} ({})", args, e))?;
+    if !status.success() {
+        return Err(format!("git 命令执行失败，退出码: {:?}", status.code()));
+    }
+    Ok(())
+}
+
+// 浅克隆（或按 revision 完整克隆）目标仓库到缓存目录，返回克隆后的本地路径
+fn ensure_git_checkout(git: &GitSpec) -> Result<PathBuf, String> {
+    if git.branch.is_some() && git.revision.is_some() {
+        return Err("branch 与 revision 只能指定一个".to_string());
+    }
+    validate_repo_url(&git.repo_url)?;
+
+    let cache_dir = git_cache_dir(&git.repo_url);
+    if cache_dir.exists() {
+        std::fs::remove_dir_all(&cache_dir).map_err(|e| format!("清理旧的克隆缓存失败: {}", e))?;
+    }
+    std::fs::create_dir_all(cache_dir.parent().unwrap()).map_err(|e| format!("创建缓存目录失败: {}", e))?;
+
+    let cache_dir_str = cache_dir.to_string_lossy().to_string();
+
+    if let Some(revision) = &git.revision {
+        // 指定 commit 时无法浅克隆，需要完整克隆后再 checkout
+        // revision 以 "-" 开头会被 git 当成选项解析，直接拒绝
+        if revision.starts_with('-') {
+            return Err(format!("不支持的 revision: {}", revision));
+        }
+        // "--" 确保 repo_url/cache_dir_str 一定被当作位置参数，不会被 git 当成选项解析
+        run_git(&["clone", "--", &git.repo_url, &cache_dir_str], None)?;
+        // revision 必须放在 "--" 之前，放在之后会被 git 当成文件路径
+        run_git(&["checkout", revision, "--"], Some(&cache_dir))?;
+    } else {
+        let branch = git.branch.clone().unwrap_or_else(|| "master".to_string());
+        let result = run_git(
+            &["clone", "--depth", "1", "--branch", &branch, "--", &git.repo_url, &cache_dir_str],
+            None,
+        );
+
+        // 未显式指定分支时，默认分支名在不同仓库上可能是 master 或 main，失败后互相兜底重试一次
+        if git.branch.is_none() && result.is_err() {
+            run_git(
+                &["clone", "--depth", "1", "--branch", "main", "--", &git.repo_url, &cache_dir_str],
+                None,
+            )?;
+        } else {
+            result?;
+        }
+    }
+
+    Ok(cache_dir)
+}
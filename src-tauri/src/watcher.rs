@@ -0,0 +1,88 @@
+// 文件监听子系统：打开文件后持续盯着它，外部改动（git pull、其它编辑器、同步工具）时
+// 通知前端刷新，而不是让已打开的标签页悄悄过时
+
+use crate::app;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+use tauri::Emitter;
+
+fn watchers() -> &'static Mutex<HashMap<String, RecommendedWatcher>> {
+    static WATCHERS: OnceLock<Mutex<HashMap<String, RecommendedWatcher>>> = OnceLock::new();
+    WATCHERS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+// 注册一个监听，键是规范化后的绝对路径，重复 open 同一个文件不会产生多余的监听器
+pub fn start_watching(app_handle: tauri::AppHandle, path: &str) -> Result<(), String> {
+    let key = app::to_absolute_path(path);
+
+    let mut watchers = watchers().lock().map_err(|_| "文件监听表已损坏".to_string())?;
+    if watchers.contains_key(&key) {
+        return Ok(());
+    }
+
+    let watched_path = key.clone();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| match res {
+        Ok(event) => handle_event(&app_handle, &watched_path, event),
+        Err(e) => eprintln!("文件监听出错: {:?}", e),
+    })
+    .map_err(|e| format!("创建文件监听器失败: {}", e))?;
+
+    watcher
+        .watch(Path::new(&key), RecursiveMode::NonRecursive)
+        .map_err(|e| format!("注册文件监听失败: {}", e))?;
+
+    println!("开始监听文件: {}", key);
+    watchers.insert(key, watcher);
+    Ok(())
+}
+
+pub fn stop_watching(path: &str) -> Result<(), String> {
+    let key = app::to_absolute_path(path);
+
+    let mut watchers = watchers().lock().map_err(|_| "文件监听表已损坏".to_string())?;
+    if let Some(mut watcher) = watchers.remove(&key) {
+        let _ = watcher.unwatch(Path::new(&key));
+        println!("停止监听文件: {}", key);
+    }
+    Ok(())
+}
+
+fn handle_event(app_handle: &tauri::AppHandle, path: &str, event: Event) {
+    match event.kind {
+        EventKind::Modify(_) => {
+            println!("检测到文件变更: {}", path);
+            if let Err(e) = app_handle.emit("file-changed", path) {
+                eprintln!("发送文件变更事件失败: {:?}", e);
+            }
+        }
+        EventKind::Remove(_) => {
+            if Path::new(path).exists() {
+                // vim/VS Code/Sublime 等编辑器保存时通常是"写临时文件再重命名覆盖"，
+                // 这会让原 inode 的监听触发 Remove，但这个路径上其实还有文件（新内容）。
+                // 当作变更处理，并在同一路径上重新挂载监听（旧的监听已经随原 inode 失效）
+                println!("检测到文件被重命名覆盖（多半是保存操作），重新挂载监听: {}", path);
+                if let Err(e) = app_handle.emit("file-changed", path) {
+                    eprintln!("发送文件变更事件失败: {:?}", e);
+                }
+                if let Ok(mut watchers) = watchers().lock() {
+                    watchers.remove(path);
+                }
+                if let Err(e) = start_watching(app_handle.clone(), path) {
+                    eprintln!("重新注册文件监听失败: {}", e);
+                }
+            } else {
+                println!("检测到文件被删除: {}", path);
+                if let Err(e) = app_handle.emit("file-removed", path) {
+                    eprintln!("发送文件删除事件失败: {:?}", e);
+                }
+                // 文件本体已经消失，监听器也没有存在的意义了
+                if let Ok(mut watchers) = watchers().lock() {
+                    watchers.remove(path);
+                }
+            }
+        }
+        _ => {}
+    }
+}